@@ -0,0 +1,14 @@
+use std::process::Command;
+
+fn main() {
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+
+    if let Some(commit) = commit {
+        println!("cargo:rustc-env=GIT_COMMIT_HASH={}", commit.trim());
+    }
+}
@@ -1,4 +1,9 @@
-use actix_web::{web, App, HttpResponse, HttpServer, Responder};
+use actix_files::{Files, NamedFile};
+use actix_web::{
+    middleware::{Logger, NormalizePath, TrailingSlash},
+    web, App, HttpRequest, HttpResponse, HttpServer, Responder,
+};
+use actix_web_codegen::get;
 use serde::Serialize;
 
 #[derive(Serialize)]
@@ -11,24 +16,130 @@ struct Health {
     status: String,
 }
 
+#[derive(Serialize)]
+struct Version {
+    name: String,
+    version: String,
+    git_commit: String,
+}
+
+#[derive(Serialize)]
+struct HeaderPair {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct EchoResponse {
+    method: String,
+    path: String,
+    host: String,
+    headers: Vec<HeaderPair>,
+}
+
+#[derive(Serialize)]
+struct NotFound {
+    error: String,
+    path: String,
+}
+
+#[get("/")]
 async fn index() -> impl Responder {
     HttpResponse::Ok().json(Message {
         message: "Rust Actix API - Testing Auto-Docker Extension".to_string(),
     })
 }
 
+#[get("/health")]
 async fn health() -> impl Responder {
     HttpResponse::Ok().json(Health {
         status: "healthy".to_string(),
     })
 }
 
+#[get("/version")]
+async fn version() -> impl Responder {
+    HttpResponse::Ok().json(Version {
+        name: env!("CARGO_PKG_NAME").to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: option_env!("GIT_COMMIT_HASH").unwrap_or("unknown").to_string(),
+    })
+}
+
+fn debug_echo_response(req: &HttpRequest) -> HttpResponse {
+    let headers = req
+        .headers()
+        .iter()
+        .map(|(name, value)| HeaderPair {
+            name: name.to_string(),
+            value: value
+                .to_str()
+                .unwrap_or("<non-utf8>")
+                .to_string(),
+        })
+        .collect();
+
+    HttpResponse::Ok().json(EchoResponse {
+        method: req.method().to_string(),
+        path: req.uri().path().to_string(),
+        host: req
+            .connection_info()
+            .host()
+            .to_string(),
+        headers,
+    })
+}
+
+// Reachable at /api/v1/debug/echo (nested under the versioned API scope in
+// main()), not the bare /debug/echo path this route was originally added at —
+// a direct hit on /debug/echo now falls through to spa_fallback instead.
+#[get("/debug/echo")]
+async fn debug_echo(req: HttpRequest) -> impl Responder {
+    debug_echo_response(&req)
+}
+
+fn not_found_response(req: &HttpRequest) -> HttpResponse {
+    HttpResponse::NotFound().json(NotFound {
+        error: "not_found".to_string(),
+        path: req.path().to_string(),
+    })
+}
+
+// Unmatched GET requests that aren't under /api fall through to this handler so
+// client-side routes (e.g. /dashboard) load the SPA shell instead of 404ing.
+// Everything else (unmatched API routes, other methods) gets a structured JSON 404.
+//
+// `Files` is deliberately NOT mounted at "/" for this to work: its service guards
+// every path it owns to GET/HEAD and answers non-matching methods with its own
+// plaintext 405 before `default_service` ever runs, which would shadow this JSON
+// fallback for every verb but GET across the whole app. Static assets live under
+// the narrower "/static" prefix instead, so this handler owns "/" for every method.
+async fn spa_fallback(req: HttpRequest) -> actix_web::Result<HttpResponse> {
+    let path = req.path();
+    if req.method() == actix_web::http::Method::GET && !path.starts_with("/api") {
+        return Ok(NamedFile::open("static/index.html")?.into_response(&req));
+    }
+
+    Ok(not_found_response(&req))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
+
     HttpServer::new(|| {
         App::new()
-            .route("/", web::get().to(index))
-            .route("/health", web::get().to(health))
+            .wrap(Logger::new("%a \"%r\" %s %b \"%{Referer}i\" %D ms"))
+            .wrap(NormalizePath::new(TrailingSlash::Trim))
+            .service(index)
+            .service(
+                web::scope("/api/v1")
+                    .service(health)
+                    .service(version)
+                    .service(debug_echo),
+            )
+            .service(Files::new("/static", "./static"))
+            .default_service(web::route().to(spa_fallback))
     })
     .bind(("0.0.0.0", 8080))?
     .run()